@@ -0,0 +1,43 @@
+use auto_launch::AutoLaunch;
+use tauri::AppHandle;
+
+use crate::preferences;
+
+fn auto_launch_handle(app: &AppHandle) -> Result<AutoLaunch, String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let exe_path = exe
+        .to_str()
+        .ok_or("Executable path is not valid UTF-8")?;
+
+    Ok(AutoLaunch::new(
+        &app.package_info().name,
+        exe_path,
+        &[] as &[&str],
+    ))
+}
+
+/// Registers (or unregisters) the app to start on login. Idempotent: only
+/// touches the OS registration when the desired state differs from the
+/// actual one, since some platforms error on a redundant enable/disable.
+#[tauri::command]
+pub fn set_auto_launch(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let launcher = auto_launch_handle(&app)?;
+    let is_enabled = launcher.is_enabled().map_err(|e| e.to_string())?;
+
+    if is_enabled != enabled {
+        if enabled {
+            launcher.enable().map_err(|e| e.to_string())?;
+        } else {
+            launcher.disable().map_err(|e| e.to_string())?;
+        }
+    }
+
+    let mut prefs = preferences::load(&app);
+    prefs.auto_launch = enabled;
+    preferences::save(&app, &prefs)
+}
+
+#[tauri::command]
+pub fn get_auto_launch(app: AppHandle) -> bool {
+    preferences::load(&app).auto_launch
+}
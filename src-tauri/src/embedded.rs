@@ -0,0 +1,83 @@
+//! Embedded-backend mode: runs the backend's axum router inside the Tauri
+//! process and reaches it through a `decisiongpt://` custom protocol instead
+//! of a sidecar process talking over `http://localhost:8000`. This removes
+//! the fixed TCP port, the CORS workaround, and the localhost race between
+//! the frontend loading and `check_backend_health` succeeding.
+//!
+//! Selected via `DECISIONGPT_EMBEDDED_BACKEND=1`; the sidecar path in
+//! [`crate::supervisor`] remains the default until this is proven out.
+
+use axum::body::to_bytes;
+use axum::extract::Request as AxumRequest;
+use tauri::{AppHandle, Manager};
+use tower::{Service, ServiceExt};
+
+/// Holds the backend's axum router so the protocol handler can drive it
+/// in-process. `axum::Router` is cheaply `Clone` (it's `Arc`-backed
+/// internally) and already `Send + Sync`, so no lock is needed to share it.
+pub struct EmbeddedBackend(axum::Router);
+
+impl EmbeddedBackend {
+    pub fn new(router: axum::Router) -> Self {
+        EmbeddedBackend(router)
+    }
+}
+
+/// Whether embedded mode should be used instead of the sidecar process.
+pub fn is_enabled() -> bool {
+    std::env::var("DECISIONGPT_EMBEDDED_BACKEND")
+        .map(|v| v == "1")
+        .unwrap_or(false)
+}
+
+/// Converts a Tauri custom-protocol request into an `axum::extract::Request`,
+/// drives it through the embedded router, and converts the response back.
+pub async fn process_tauri_request(
+    app: &AppHandle,
+    request: tauri::http::Request<Vec<u8>>,
+) -> Result<tauri::http::Response<Vec<u8>>, String> {
+    let state = app
+        .try_state::<EmbeddedBackend>()
+        .ok_or("Embedded backend is not managed; is DECISIONGPT_EMBEDDED_BACKEND set?")?;
+    let mut router = state.0.clone();
+
+    let (parts, body) = request.into_parts();
+    let axum_request = AxumRequest::from_parts(parts, axum::body::Body::from(body));
+
+    let response = router
+        .ready()
+        .await
+        .map_err(|e| e.to_string())?
+        .call(axum_request)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let (resp_parts, resp_body) = response.into_parts();
+    let bytes = to_bytes(resp_body, usize::MAX)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(tauri::http::Response::from_parts(resp_parts, bytes.to_vec()))
+}
+
+/// Drives a synthetic `GET /health` through the embedded router so
+/// `check_backend_health` has a readiness signal in embedded mode, where
+/// nothing is listening on port 8000. Returns `Ok(false)` rather than an
+/// error if the router isn't managed yet, matching the "not running" result
+/// the sidecar path returns for a connection refused.
+pub async fn probe_health(app: &AppHandle) -> Result<bool, String> {
+    if app.try_state::<EmbeddedBackend>().is_none() {
+        return Ok(false);
+    }
+
+    let request = tauri::http::Request::builder()
+        .method("GET")
+        .uri("/health")
+        .body(Vec::new())
+        .map_err(|e| e.to_string())?;
+
+    match process_tauri_request(app, request).await {
+        Ok(response) => Ok(response.status().is_success()),
+        Err(_) => Ok(false),
+    }
+}
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Typed failures from app setup, covering data-dir creation, sidecar spawn,
+/// and DB-path resolution so `setup` can show the user something more
+/// actionable than a swallowed `eprintln!`.
+#[derive(Debug, Error)]
+pub enum SetupError {
+    #[error("Could not create the app data directory: {0}")]
+    AppDataDir(String),
+    #[error("Could not prepare the database path: {0}")]
+    DbPath(String),
+    #[error("Could not start the backend: {0}")]
+    SidecarSpawn(String),
+}
@@ -0,0 +1,149 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+/// How many rotated `backend-*.log` files to keep around before pruning.
+const MAX_LOG_FILES: usize = 14;
+
+fn logs_dir(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver().app_data_dir().map(|p| p.join("logs"))
+}
+
+/// Appends a single line to today's backend log file, creating the logs
+/// directory on demand and rotating out old files once there are more than
+/// `MAX_LOG_FILES`.
+pub fn append_backend_log(app: &AppHandle, line: &str) {
+    let Some(dir) = logs_dir(app) else {
+        return;
+    };
+    if fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+
+    let path = dir.join(format!("backend-{}.log", today_stamp()));
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "[{}] {}", now_stamp(), line);
+    }
+
+    rotate_old_logs(&dir);
+}
+
+/// Returns the contents of the most recently modified backend log file, if any.
+pub fn latest_log_contents(app: &AppHandle) -> Option<String> {
+    let dir = logs_dir(app)?;
+    fs::read_to_string(newest_log_path(&dir)?).ok()
+}
+
+fn is_backend_log(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| n.starts_with("backend-") && n.ends_with(".log"))
+        .unwrap_or(false)
+}
+
+fn newest_log_path(dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| is_backend_log(&e.path()))
+        .max_by_key(|e| e.metadata().and_then(|m| m.modified()).unwrap_or(UNIX_EPOCH))
+        .map(|e| e.path())
+}
+
+fn rotate_old_logs(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut files: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| is_backend_log(&e.path()))
+        .collect();
+
+    if files.len() <= MAX_LOG_FILES {
+        return;
+    }
+
+    files.sort_by_key(|e| e.metadata().and_then(|m| m.modified()).unwrap_or(UNIX_EPOCH));
+    for stale in &files[..files.len() - MAX_LOG_FILES] {
+        let _ = fs::remove_file(stale.path());
+    }
+}
+
+fn now_stamp() -> String {
+    let secs = unix_secs();
+    let (y, m, d) = civil_from_days((secs / 86400) as i64);
+    let rem = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        y,
+        m,
+        d,
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60
+    )
+}
+
+fn today_stamp() -> String {
+    let secs = unix_secs();
+    let (y, m, d) = civil_from_days((secs / 86400) as i64);
+    format!("{:04}{:02}{:02}", y, m, d)
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Howard Hinnant's `civil_from_days`: turns a day count since the Unix epoch
+/// into a (year, month, day) triple without pulling in a date/time crate just
+/// for log filenames.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_from_days_handles_the_epoch() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn civil_from_days_handles_leap_day_rollover() {
+        // 2024 is a leap year: day 19782 is Feb 29th, and the next day
+        // rolls over into March rather than a nonexistent Feb 30th.
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+        assert_eq!(civil_from_days(19783), (2024, 3, 1));
+    }
+
+    #[test]
+    fn civil_from_days_handles_century_non_leap_years() {
+        // 1900 isn't a leap year (divisible by 100, not 400), so this day
+        // count lands on March 1st rather than a Feb 29th that didn't exist.
+        assert_eq!(civil_from_days(-25508), (1900, 3, 1));
+        // 2000 is a leap year (divisible by 400).
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+    }
+
+    #[test]
+    fn civil_from_days_handles_far_future_non_leap_century() {
+        assert_eq!(civil_from_days(47540), (2100, 2, 28));
+    }
+}
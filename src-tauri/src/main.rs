@@ -3,14 +3,18 @@
     windows_subsystem = "windows"
 )]
 
-use std::collections::HashMap;
-use std::process::Child;
-use std::sync::Mutex;
-use tauri::api::process::{Command, CommandEvent};
-use tauri::AppHandle;
+mod autostart;
+mod embedded;
+mod errors;
+mod logging;
+mod ollama;
+mod preferences;
+mod supervisor;
+mod tray;
 
-// State to hold the backend process
-struct BackendProcess(Mutex<Option<Child>>);
+use tauri::{AppHandle, Manager};
+
+use errors::SetupError;
 
 #[tauri::command]
 fn get_app_data_dir(app: AppHandle) -> String {
@@ -71,7 +75,11 @@ async fn check_ollama_status() -> Result<serde_json::Value, String> {
 }
 
 #[tauri::command]
-async fn check_backend_health() -> Result<bool, String> {
+pub(crate) async fn check_backend_health(app: AppHandle) -> Result<bool, String> {
+    if embedded::is_enabled() {
+        return embedded::probe_health(&app).await;
+    }
+
     let client = reqwest::Client::new();
 
     match client
@@ -85,69 +93,83 @@ async fn check_backend_health() -> Result<bool, String> {
     }
 }
 
-fn start_backend_sidecar(app: &AppHandle) -> Result<(), String> {
-    let app_data_dir = app
-        .path_resolver()
-        .app_data_dir()
-        .ok_or("Failed to get app data directory")?;
-
-    // Create data directory if it doesn't exist
-    std::fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
-
-    let sqlite_path = app_data_dir.join("data").join("decisiongpt.db");
+#[tauri::command]
+fn get_last_log_file(app: AppHandle) -> Option<String> {
+    logging::latest_log_contents(&app)
+}
 
-    // Ensure the data subdirectory exists
-    if let Some(parent) = sqlite_path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
-    }
+/// Bundles the latest backend log, Ollama status, and basic OS/version info
+/// into a single report a user can paste into a bug report.
+#[tauri::command]
+async fn export_diagnostics(app: AppHandle) -> Result<String, String> {
+    let log =
+        logging::latest_log_contents(&app).unwrap_or_else(|| "No backend log available".into());
+    let ollama_status = check_ollama_status()
+        .await
+        .unwrap_or_else(|e| serde_json::json!({ "status": "unknown", "error": e }));
+
+    Ok(format!(
+        "=== DecisionGPT Diagnostics ===\nOS: {} ({})\nApp version: {}\n\n--- Ollama status ---\n{}\n\n--- Backend log (latest) ---\n{}\n",
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        env!("CARGO_PKG_VERSION"),
+        serde_json::to_string_pretty(&ollama_status).unwrap_or_default(),
+        log
+    ))
+}
 
-    println!("Starting backend sidecar...");
-    println!("SQLite path: {:?}", sqlite_path);
-
-    // Spawn the backend sidecar process
-    let mut envs = HashMap::new();
-    envs.insert("DATABASE_TYPE".to_string(), "sqlite".to_string());
-    envs.insert("SQLITE_PATH".to_string(), sqlite_path.to_str().unwrap_or("").to_string());
-    envs.insert("LLM_PROVIDER".to_string(), "ollama".to_string());
-    envs.insert("DESKTOP_MODE".to_string(), "true".to_string());
-    envs.insert("CORS_ORIGINS".to_string(), "http://localhost:3000,tauri://localhost".to_string());
-
-    let (mut rx, _child) = Command::new_sidecar("decisiongpt-backend")
-        .map_err(|e| format!("Failed to create sidecar command: {}", e))?
-        .envs(envs)
-        .spawn()
-        .map_err(|e| format!("Failed to spawn backend: {}", e))?;
-
-    // Monitor backend output in a separate task
-    tauri::async_runtime::spawn(async move {
-        while let Some(event) = rx.recv().await {
-            match event {
-                CommandEvent::Stdout(line) => println!("[backend] {}", line),
-                CommandEvent::Stderr(line) => eprintln!("[backend error] {}", line),
-                CommandEvent::Error(err) => eprintln!("[backend error] {}", err),
-                CommandEvent::Terminated(payload) => {
-                    println!("[backend] Process terminated: {:?}", payload);
-                    break;
-                }
-                _ => {}
-            }
+/// Shows a native error dialog for a setup failure, with a button to open
+/// the logs directory so the user has somewhere to go besides a dead window.
+fn show_setup_error_dialog(app: &AppHandle, error: &SetupError) {
+    let window = app.get_window("main");
+    let open_logs = tauri::api::dialog::blocking::ask(
+        window.as_ref(),
+        "DecisionGPT failed to start",
+        format!("{}\n\nOpen the logs folder?", error),
+    );
+
+    if open_logs {
+        if let Some(logs_dir) = app.path_resolver().app_data_dir().map(|p| p.join("logs")) {
+            let _ = tauri::api::shell::open(
+                &app.shell_scope(),
+                logs_dir.to_string_lossy().to_string(),
+                None,
+            );
         }
-    });
-
-    println!("Backend sidecar started");
-    Ok(())
+    }
 }
 
 fn main() {
     tauri::Builder::default()
-        .manage(BackendProcess(Mutex::new(None)))
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            tray::show_main_window(app);
+        }))
+        .manage(supervisor::BackendProcess::new())
+        .system_tray(tray::build())
+        .on_system_tray_event(tray::handle_event)
+        .register_uri_scheme_protocol("decisiongpt", |app, request| {
+            tauri::async_runtime::block_on(embedded::process_tauri_request(app, request))
+                .map_err(|e| e.into())
+        })
         .setup(|app| {
             let handle = app.handle();
 
-            // Start backend sidecar on app startup
-            if let Err(e) = start_backend_sidecar(&handle) {
-                eprintln!("Failed to start backend: {}", e);
-                // Don't fail the app, user can start backend manually
+            if embedded::is_enabled() {
+                // Embedded mode: drive the backend's axum router in-process
+                // behind the `decisiongpt://` protocol registered above.
+                app.manage(embedded::EmbeddedBackend::new(decisiongpt_backend::router()));
+            } else {
+                // Sidecar mode (default): spawn the backend process and its
+                // health watchdog. Surface the initial failure to the user;
+                // the supervisor keeps retrying with backoff afterwards.
+                if let Err(e) = supervisor::spawn_supervised(handle.clone()) {
+                    show_setup_error_dialog(&handle, &e);
+                }
+            }
+
+            // Minimize to tray instead of quitting so the backend keeps serving.
+            if let Some(window) = app.get_window("main") {
+                window.on_window_event(tray::intercept_close);
             }
 
             Ok(())
@@ -156,7 +178,22 @@ fn main() {
             get_app_data_dir,
             check_ollama_status,
             check_backend_health,
+            get_last_log_file,
+            export_diagnostics,
+            supervisor::restart_backend,
+            supervisor::stop_backend,
+            autostart::set_auto_launch,
+            autostart::get_auto_launch,
+            ollama::pull_ollama_model,
+            ollama::delete_ollama_model,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                // Make sure the sidecar doesn't outlive the window so it
+                // doesn't leak as an orphan process.
+                let _ = supervisor::stop(app_handle);
+            }
+        });
 }
@@ -0,0 +1,81 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{AppHandle, Manager};
+
+const OLLAMA_BASE_URL: &str = "http://localhost:11434";
+const OLLAMA_HELP: &str = "Please install and start Ollama from https://ollama.ai";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PullProgress {
+    status: String,
+    #[serde(default)]
+    completed: Option<u64>,
+    #[serde(default)]
+    total: Option<u64>,
+}
+
+/// Pulls an Ollama model, re-emitting each progress line from the streamed
+/// NDJSON response as an `ollama-pull-progress` event so the frontend can
+/// render a progress bar.
+#[tauri::command]
+pub async fn pull_ollama_model(app: AppHandle, name: String) -> Result<(), String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/api/pull", OLLAMA_BASE_URL))
+        .json(&json!({ "name": name, "stream": true }))
+        .send()
+        .await
+        .map_err(|_| format!("Could not reach Ollama. {}", OLLAMA_HELP))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Ollama rejected the pull request for '{}'", name));
+    }
+
+    let mut stream = response.bytes_stream();
+    // Buffered as raw bytes rather than decoded per-chunk: a multi-byte
+    // UTF-8 character can straddle a chunk boundary, and decoding each chunk
+    // independently would replace both halves with U+FFFD instead of
+    // reassembling it.
+    let mut buf: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        buf.extend_from_slice(&chunk);
+
+        while let Some(newline) = buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buf.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line[..line.len() - 1]);
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Ok(progress) = serde_json::from_str::<PullProgress>(line) {
+                let _ = app.emit_all("ollama-pull-progress", &progress);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Deletes a locally pulled Ollama model.
+#[tauri::command]
+pub async fn delete_ollama_model(name: String) -> Result<(), String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .delete(format!("{}/api/delete", OLLAMA_BASE_URL))
+        .json(&json!({ "name": name }))
+        .send()
+        .await
+        .map_err(|_| format!("Could not reach Ollama. {}", OLLAMA_HELP))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("Ollama failed to delete model '{}'", name))
+    }
+}
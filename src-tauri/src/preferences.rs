@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// User-configurable preferences that need to survive app restarts.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Preferences {
+    #[serde(default)]
+    pub auto_launch: bool,
+}
+
+fn preferences_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|p| p.join("preferences.json"))
+}
+
+/// Loads preferences from disk, falling back to defaults if the file is
+/// missing or unreadable.
+pub fn load(app: &AppHandle) -> Preferences {
+    preferences_path(app)
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(app: &AppHandle, prefs: &Preferences) -> Result<(), String> {
+    let path = preferences_path(app).ok_or("Failed to get app data directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(prefs).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
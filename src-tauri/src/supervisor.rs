@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::api::process::{Command, CommandChild, CommandEvent};
+use tauri::{AppHandle, Manager};
+
+use crate::errors::SetupError;
+use crate::logging;
+
+/// Tracks the currently running backend sidecar, if any, so it can be killed
+/// cleanly on exit or restarted instead of leaking as an orphan process.
+pub struct BackendProcess {
+    /// The tracked child, tagged with the generation it was spawned under.
+    /// The generation lets a `Terminated` event for a since-replaced child
+    /// recognize it's stale instead of clobbering the current one.
+    child: Mutex<Option<(u64, CommandChild)>>,
+    next_generation: AtomicU64,
+    /// Claimed for the duration of a restart attempt (or backoff chain) so
+    /// the `Terminated` handler and the health watchdog's forced restart
+    /// can't both spawn a sidecar for the same death and race two processes
+    /// for port 8000 / the sqlite file.
+    restart_in_flight: AtomicBool,
+}
+
+impl BackendProcess {
+    pub fn new() -> Self {
+        BackendProcess {
+            child: Mutex::new(None),
+            next_generation: AtomicU64::new(1),
+            restart_in_flight: AtomicBool::new(false),
+        }
+    }
+}
+
+/// Claims the in-flight restart guard. Returns `false` if a restart (or
+/// backoff chain) is already underway, in which case the caller should skip
+/// starting another one.
+fn begin_restart(app: &AppHandle) -> bool {
+    app.state::<BackendProcess>()
+        .restart_in_flight
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_ok()
+}
+
+/// Releases the in-flight restart guard once a chain has concluded, whether
+/// by success or by exhausting its retries.
+fn end_restart(app: &AppHandle) {
+    app.state::<BackendProcess>()
+        .restart_in_flight
+        .store(false, Ordering::SeqCst);
+}
+
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(10);
+const HEALTH_FAILURE_THRESHOLD: u32 = 3;
+
+/// Spawns the backend sidecar and starts the background health watchdog.
+/// Call once from `setup`. Returns the error from the *initial* spawn attempt
+/// so the caller can surface it to the user; background retries after that
+/// are handled internally and only logged.
+pub fn spawn_supervised(app: AppHandle) -> Result<(), SetupError> {
+    let result = match start(&app) {
+        Ok((generation, child)) => {
+            *app.state::<BackendProcess>().child.lock().unwrap() = Some((generation, child));
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Failed to start backend: {}", e);
+            if begin_restart(&app) {
+                schedule_restart(app.clone(), 0);
+            }
+            Err(e)
+        }
+    };
+    spawn_health_watchdog(app);
+    result
+}
+
+fn spawn_attempt(app: AppHandle, attempt: u32) {
+    match start(&app) {
+        Ok((generation, child)) => {
+            *app.state::<BackendProcess>().child.lock().unwrap() = Some((generation, child));
+            end_restart(&app);
+        }
+        Err(e) => {
+            eprintln!("Failed to start backend: {}", e);
+            schedule_restart(app, attempt);
+        }
+    }
+}
+
+fn start(app: &AppHandle) -> Result<(u64, CommandChild), SetupError> {
+    let generation = app
+        .state::<BackendProcess>()
+        .next_generation
+        .fetch_add(1, Ordering::SeqCst);
+
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| SetupError::AppDataDir("Failed to get app data directory".into()))?;
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| SetupError::AppDataDir(e.to_string()))?;
+
+    let sqlite_path = app_data_dir.join("data").join("decisiongpt.db");
+    if let Some(parent) = sqlite_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| SetupError::DbPath(e.to_string()))?;
+    }
+
+    println!("Starting backend sidecar...");
+    println!("SQLite path: {:?}", sqlite_path);
+
+    let mut envs = HashMap::new();
+    envs.insert("DATABASE_TYPE".to_string(), "sqlite".to_string());
+    envs.insert(
+        "SQLITE_PATH".to_string(),
+        sqlite_path.to_str().unwrap_or("").to_string(),
+    );
+    envs.insert("LLM_PROVIDER".to_string(), "ollama".to_string());
+    envs.insert("DESKTOP_MODE".to_string(), "true".to_string());
+    envs.insert(
+        "CORS_ORIGINS".to_string(),
+        "http://localhost:3000,tauri://localhost".to_string(),
+    );
+
+    let (mut rx, child) = Command::new_sidecar("decisiongpt-backend")
+        .map_err(|e| SetupError::SidecarSpawn(format!("Failed to create sidecar command: {}", e)))?
+        .envs(envs)
+        .spawn()
+        .map_err(|e| SetupError::SidecarSpawn(format!("Failed to spawn backend: {}", e)))?;
+
+    // Monitor backend output in a separate task, teeing every line into the
+    // rotating log file and restarting the sidecar if it terminates.
+    let monitor_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => {
+                    println!("[backend] {}", line);
+                    logging::append_backend_log(&monitor_handle, &line);
+                }
+                CommandEvent::Stderr(line) => {
+                    eprintln!("[backend error] {}", line);
+                    logging::append_backend_log(&monitor_handle, &format!("[stderr] {}", line));
+                }
+                CommandEvent::Error(err) => {
+                    eprintln!("[backend error] {}", err);
+                    logging::append_backend_log(&monitor_handle, &format!("[error] {}", err));
+                }
+                CommandEvent::Terminated(payload) => {
+                    println!("[backend] Process terminated: {:?}", payload);
+                    logging::append_backend_log(
+                        &monitor_handle,
+                        &format!("[terminated] {:?}", payload),
+                    );
+
+                    // Only react if we're still the tracked child. A
+                    // manual/watchdog restart may have already replaced us
+                    // with a newer generation by the time our own
+                    // `Terminated` event arrives; if so, this is a stale
+                    // notification for an already-superseded process and
+                    // must not clobber the current one or spawn a third.
+                    let mut tracked = monitor_handle.state::<BackendProcess>().child.lock().unwrap();
+                    let is_current = matches!(&*tracked, Some((gen, _)) if *gen == generation);
+                    if is_current {
+                        *tracked = None;
+                    }
+                    drop(tracked);
+
+                    if !is_current {
+                        println!(
+                            "[backend] Terminated event for stale sidecar generation {}, ignoring",
+                            generation
+                        );
+                        break;
+                    }
+
+                    if begin_restart(&monitor_handle) {
+                        schedule_restart(monitor_handle.clone(), 0);
+                    } else {
+                        println!(
+                            "[backend] Restart already in flight, skipping duplicate restart for Terminated event"
+                        );
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
+    println!("Backend sidecar started");
+    Ok((generation, child))
+}
+
+/// The backoff delay before a given restart attempt (1-indexed): doubles
+/// each attempt starting from `BASE_BACKOFF`, capped at `MAX_BACKOFF`.
+fn backoff_for_attempt(attempt: u32) -> Duration {
+    (BASE_BACKOFF * 2u32.pow(attempt - 1)).min(MAX_BACKOFF)
+}
+
+fn schedule_restart(app: AppHandle, previous_attempts: u32) {
+    let attempt = previous_attempts + 1;
+    if attempt > MAX_RESTART_ATTEMPTS {
+        eprintln!(
+            "Backend sidecar failed {} times in a row, giving up on auto-restart",
+            MAX_RESTART_ATTEMPTS
+        );
+        end_restart(&app);
+        return;
+    }
+
+    let backoff = backoff_for_attempt(attempt);
+    println!(
+        "Restarting backend sidecar in {:?} (attempt {}/{})",
+        backoff, attempt, MAX_RESTART_ATTEMPTS
+    );
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(backoff).await;
+        spawn_attempt(app, attempt);
+    });
+}
+
+/// Polls `/health` so that a backend wedged in a non-responsive state (no
+/// `Terminated` event, just a hung process) still gets force-restarted.
+fn spawn_health_watchdog(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut consecutive_failures = 0u32;
+        loop {
+            tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+
+            let is_tracked = app.state::<BackendProcess>().child.lock().unwrap().is_some();
+            if !is_tracked {
+                // Nothing tracked: either between restart attempts or the
+                // user explicitly stopped the backend. Don't pile on.
+                consecutive_failures = 0;
+                continue;
+            }
+
+            match crate::check_backend_health(app.clone()).await {
+                Ok(true) => consecutive_failures = 0,
+                _ => {
+                    consecutive_failures += 1;
+                    if consecutive_failures >= HEALTH_FAILURE_THRESHOLD {
+                        eprintln!(
+                            "Backend health check failed {} times with no Terminated event; forcing restart",
+                            consecutive_failures
+                        );
+                        consecutive_failures = 0;
+                        if let Err(e) = restart(app.clone()) {
+                            println!(
+                                "[backend] Health watchdog force-restart not performed: {}",
+                                e
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Kills the tracked sidecar, if any.
+pub fn stop(app: &AppHandle) -> Result<(), String> {
+    if let Some((_, child)) = app.state::<BackendProcess>().child.lock().unwrap().take() {
+        child.kill().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Kills the tracked sidecar, if any, and starts a fresh one. Shares the
+/// restart-in-flight guard with the `Terminated`-driven backoff chain so a
+/// manual or watchdog-forced restart can't race it into spawning two
+/// sidecars; returns an error if a restart is already underway.
+pub fn restart(app: AppHandle) -> Result<(), String> {
+    if !begin_restart(&app) {
+        return Err("A backend restart is already in progress".to_string());
+    }
+    if let Err(e) = stop(&app) {
+        end_restart(&app);
+        return Err(e);
+    }
+    spawn_attempt(app, 0);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_backend(app: AppHandle) -> Result<(), String> {
+    stop(&app)
+}
+
+#[tauri::command]
+pub fn restart_backend(app: AppHandle) -> Result<(), String> {
+    restart(app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff_for_attempt(1), Duration::from_secs(1));
+        assert_eq!(backoff_for_attempt(2), Duration::from_secs(2));
+        assert_eq!(backoff_for_attempt(3), Duration::from_secs(4));
+        assert_eq!(backoff_for_attempt(4), Duration::from_secs(8));
+        assert_eq!(backoff_for_attempt(5), Duration::from_secs(16));
+    }
+
+    #[test]
+    fn backoff_is_capped_at_max_backoff() {
+        assert_eq!(backoff_for_attempt(6), MAX_BACKOFF);
+        assert_eq!(backoff_for_attempt(10), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn max_restart_attempts_matches_the_documented_ceiling() {
+        // schedule_restart gives up once `attempt > MAX_RESTART_ATTEMPTS`,
+        // so the last attempt that's actually scheduled is this one.
+        assert_eq!(MAX_RESTART_ATTEMPTS, 5);
+    }
+}
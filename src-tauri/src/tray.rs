@@ -0,0 +1,51 @@
+use tauri::{
+    AppHandle, CustomMenuItem, GlobalWindowEvent, Manager, SystemTray, SystemTrayEvent,
+    SystemTrayMenu, SystemTrayMenuItem, WindowEvent,
+};
+
+use crate::supervisor;
+
+pub fn build() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("show", "Show DecisionGPT"))
+        .add_item(CustomMenuItem::new("restart_backend", "Restart Backend"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit", "Quit"));
+
+    SystemTray::new().with_menu(menu)
+}
+
+pub fn handle_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } | SystemTrayEvent::DoubleClick { .. } => {
+            show_main_window(app);
+        }
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            "show" => show_main_window(app),
+            "restart_backend" => {
+                let _ = supervisor::restart(app.clone());
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+/// Shows and focuses the main window. Shared by the tray's "Show" item and
+/// the single-instance handler so a second launch just refocuses the app.
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Hides the main window instead of closing it, so the backend keeps serving
+/// in the background. Register via the main window's `on_window_event`.
+pub fn intercept_close(event: &GlobalWindowEvent) {
+    if let WindowEvent::CloseRequested { api, .. } = event.event() {
+        api.prevent_close();
+        let _ = event.window().hide();
+    }
+}